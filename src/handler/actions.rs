@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chromiumoxide_cdp::cdp::browser_protocol::input::{
+    DispatchMouseEventParams, DispatchMouseEventType, MouseButton,
+};
+
+use crate::error::Result;
+use crate::handler::mouse::{MODIFIER_ALT, MODIFIER_CTRL, MODIFIER_META, MODIFIER_SHIFT};
+use crate::handler::page::{self, PageInner};
+use crate::layout::Point;
+
+/// A single sub-action within a tick of an `Actions` sequence.
+#[derive(Debug, Clone)]
+enum SubAction {
+    KeyDown(String),
+    KeyUp(String),
+    PointerMove {
+        point: Point,
+        duration: Duration,
+    },
+    PointerDown(MouseButton),
+    PointerUp(MouseButton),
+    Pause(Duration),
+}
+
+/// Builds a sequence of input "ticks" that are replayed in order by
+/// `PageInner::perform_actions`, inspired by Marionette's actions API.
+///
+/// Each call accumulates one sub-action; chaining `key_down` before `click`
+/// and `key_up` after lets a modifier be held down across a click, e.g.
+/// `.key_down("Control").click(point).key_up("Control")`.
+#[derive(Debug, Default)]
+pub struct Actions {
+    ticks: Vec<SubAction>,
+}
+
+impl Actions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn key_down(mut self, key: impl Into<String>) -> Self {
+        self.ticks.push(SubAction::KeyDown(key.into()));
+        self
+    }
+
+    pub fn key_up(mut self, key: impl Into<String>) -> Self {
+        self.ticks.push(SubAction::KeyUp(key.into()));
+        self
+    }
+
+    pub fn pointer_move(mut self, point: Point, duration: Duration) -> Self {
+        self.ticks.push(SubAction::PointerMove { point, duration });
+        self
+    }
+
+    pub fn pointer_down(mut self, button: MouseButton) -> Self {
+        self.ticks.push(SubAction::PointerDown(button));
+        self
+    }
+
+    pub fn pointer_up(mut self, button: MouseButton) -> Self {
+        self.ticks.push(SubAction::PointerUp(button));
+        self
+    }
+
+    pub fn pause(mut self, duration: Duration) -> Self {
+        self.ticks.push(SubAction::Pause(duration));
+        self
+    }
+
+    /// Moves to and clicks `point`, honoring any modifier keys currently
+    /// held down by a preceding `key_down`.
+    pub fn click(self, point: Point) -> Self {
+        self.pointer_move(point, Duration::from_millis(0))
+            .pointer_down(MouseButton::Left)
+            .pointer_up(MouseButton::Left)
+    }
+}
+
+/// Replays an `Actions` sequence by dispatching the corresponding
+/// `DispatchKeyEventParams`/`DispatchMouseEventParams` commands, honoring
+/// per-move `duration` by splitting it into interpolated `mouseMoved`
+/// events and keeping a pressed-key/button set so that a sequence which
+/// forgets to release a key still ends cleanly.
+pub(crate) async fn perform(page: &PageInner, actions: Actions) -> Result<()> {
+    let mut pressed_keys: HashSet<String> = HashSet::new();
+    let mut pressed_buttons: HashSet<MouseButton> = HashSet::new();
+    let mut position = Point::default();
+
+    for action in actions.ticks {
+        match action {
+            SubAction::KeyDown(key) => {
+                dispatch_key(page, &key, true).await?;
+                pressed_keys.insert(key);
+            }
+            SubAction::KeyUp(key) => {
+                dispatch_key(page, &key, false).await?;
+                pressed_keys.remove(&key);
+            }
+            SubAction::PointerMove { point, duration } => {
+                let modifiers = modifiers_for(&pressed_keys);
+                let steps = steps_for_duration(duration);
+                for step in 1..=steps {
+                    let t = f64::from(step) / f64::from(steps);
+                    let intermediate = Point::new(
+                        position.x + (point.x - position.x) * t,
+                        position.y + (point.y - position.y) * t,
+                    );
+                    page.execute(
+                        DispatchMouseEventParams::builder()
+                            .r#type(DispatchMouseEventType::MouseMoved)
+                            .x(intermediate.x)
+                            .y(intermediate.y)
+                            .modifiers(modifiers)
+                            .build()
+                            .unwrap(),
+                    )
+                    .await?;
+                }
+                position = point;
+            }
+            SubAction::PointerDown(button) => {
+                let modifiers = modifiers_for(&pressed_keys);
+                page.execute(
+                    DispatchMouseEventParams::builder()
+                        .r#type(DispatchMouseEventType::MousePressed)
+                        .x(position.x)
+                        .y(position.y)
+                        .button(button)
+                        .click_count(1)
+                        .modifiers(modifiers)
+                        .build()
+                        .unwrap(),
+                )
+                .await?;
+                pressed_buttons.insert(button);
+            }
+            SubAction::PointerUp(button) => {
+                let modifiers = modifiers_for(&pressed_keys);
+                page.execute(
+                    DispatchMouseEventParams::builder()
+                        .r#type(DispatchMouseEventType::MouseReleased)
+                        .x(position.x)
+                        .y(position.y)
+                        .button(button)
+                        .click_count(1)
+                        .modifiers(modifiers)
+                        .build()
+                        .unwrap(),
+                )
+                .await?;
+                pressed_buttons.remove(&button);
+            }
+            SubAction::Pause(duration) => {
+                futures_timer::Delay::new(duration).await;
+            }
+        }
+    }
+
+    // Release anything the sequence forgot to release so it doesn't leak
+    // held modifiers/buttons into the next interaction.
+    for key in pressed_keys {
+        dispatch_key(page, &key, false).await?;
+    }
+    for button in pressed_buttons {
+        page.execute(
+            DispatchMouseEventParams::builder()
+                .r#type(DispatchMouseEventType::MouseReleased)
+                .x(position.x)
+                .y(position.y)
+                .button(button)
+                .click_count(1)
+                .build()
+                .unwrap(),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Dispatches a single key down/up event, reusing `PageInner::press_key`'s
+/// resolution of whether the down event carries `text`/`KeyDown` or a plain
+/// `RawKeyDown` so a held modifier key dispatched here behaves the same as
+/// one dispatched through `press_key`.
+async fn dispatch_key(page: &PageInner, key: &str, down: bool) -> Result<()> {
+    page.execute(page::key_event_params(key, down)?).await?;
+    Ok(())
+}
+
+fn modifiers_for(pressed_keys: &HashSet<String>) -> i64 {
+    let mut modifiers = 0;
+    for key in pressed_keys {
+        modifiers |= match key.as_str() {
+            "Alt" => MODIFIER_ALT,
+            "Control" => MODIFIER_CTRL,
+            "Meta" => MODIFIER_META,
+            "Shift" => MODIFIER_SHIFT,
+            _ => 0,
+        };
+    }
+    modifiers
+}
+
+fn steps_for_duration(duration: Duration) -> u32 {
+    // One interpolated `mouseMoved` event roughly every 10ms, with at least
+    // one so a zero-duration move still dispatches a single event.
+    (duration.as_millis() / 10).max(1) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifiers_for_combines_held_keys() {
+        let mut pressed = HashSet::new();
+        assert_eq!(modifiers_for(&pressed), 0);
+
+        pressed.insert("Control".to_string());
+        pressed.insert("Shift".to_string());
+        assert_eq!(modifiers_for(&pressed), 2 | 8);
+
+        pressed.insert("Unknown".to_string());
+        assert_eq!(modifiers_for(&pressed), 2 | 8);
+    }
+
+    #[test]
+    fn steps_for_duration_has_a_minimum_of_one() {
+        assert_eq!(steps_for_duration(Duration::from_millis(0)), 1);
+        assert_eq!(steps_for_duration(Duration::from_millis(5)), 1);
+        assert_eq!(steps_for_duration(Duration::from_millis(100)), 10);
+    }
+}
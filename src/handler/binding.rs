@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+
+use chromiumoxide_cdp::cdp::browser_protocol::page::AddScriptToEvaluateOnNewDocumentParams;
+use chromiumoxide_cdp::cdp::js_protocol::runtime::{AddBindingParams, EventBindingCalled};
+
+use crate::error::{CdpError, Result};
+use crate::handler::page::PageInner;
+
+/// A Rust closure registered through `PageInner::expose_function`, invoked
+/// whenever the page calls `window.<name>(arg)`.
+pub(crate) type BindingHandler =
+    Box<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<String>> + Send>> + Send + Sync>;
+
+/// Holds the handlers registered via `expose_function`, keyed by binding
+/// name, so incoming `Runtime.bindingCalled` events can be routed to the
+/// right Rust closure.
+#[derive(Default)]
+pub(crate) struct BindingRegistry {
+    handlers: Mutex<HashMap<String, BindingHandler>>,
+}
+
+impl std::fmt::Debug for BindingRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BindingRegistry")
+            .field("handlers", &self.handlers.lock().unwrap().keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl BindingRegistry {
+    pub fn insert(&self, name: String, handler: BindingHandler) {
+        self.handlers.lock().unwrap().insert(name, handler);
+    }
+
+    /// Handles a `Runtime.bindingCalled` event by invoking the matching
+    /// closure and evaluating a script back on the page that settles the
+    /// pending JS promise with the returned value.
+    pub async fn dispatch(&self, page: &PageInner, event: EventBindingCalled) -> Result<()> {
+        let handler = {
+            let handlers = self.handlers.lock().unwrap();
+            handlers.get(&event.name).map(|_| ())
+        };
+        if handler.is_none() {
+            return Ok(());
+        }
+
+        let (call_id, arg) = split_payload(&event.payload)?;
+
+        let result = {
+            // Call the closure under the lock only to create its future, and
+            // await that future after the lock is released.
+            let fut = {
+                let handlers = self.handlers.lock().unwrap();
+                let handler = handlers
+                    .get(&event.name)
+                    .ok_or_else(|| CdpError::msg(format!("no binding named {}", event.name)))?;
+                (handler)(arg)
+            };
+            fut.await
+        };
+
+        let settle_script = match result {
+            Ok(value) => format!(
+                "window['{}']['callbacks'].get({}).resolve({})",
+                event.name,
+                call_id,
+                serde_json::to_string(&value)?
+            ),
+            Err(err) => format!(
+                "window['{}']['callbacks'].get({}).reject({:?})",
+                event.name,
+                call_id,
+                err.to_string()
+            ),
+        };
+
+        page.evaluate_expression(settle_script).await?;
+        Ok(())
+    }
+}
+
+fn split_payload(payload: &str) -> Result<(u64, String)> {
+    // The installed script serializes the call as `{"seq":<id>,"arg":<json>}`.
+    let value: serde_json::Value = serde_json::from_str(payload)?;
+    let seq = value
+        .get("seq")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| CdpError::msg("binding payload missing seq"))?;
+    let arg = value
+        .get("arg")
+        .cloned()
+        .unwrap_or(serde_json::Value::Null)
+        .to_string();
+    Ok((seq, arg))
+}
+
+/// The script installed on every new document that defines `window.<name>`
+/// as a function serializing its argument, stashing a pending promise
+/// resolver keyed by a sequence id, and invoking the native binding.
+pub(crate) fn install_script(name: &str) -> String {
+    format!(
+        r#"(() => {{
+    let seq = 0;
+    const callbacks = new Map();
+    const binding = window['{name}'];
+    window['{name}'] = (arg) => new Promise((resolve, reject) => {{
+        const id = seq++;
+        callbacks.set(id, {{ resolve, reject }});
+        binding.call(window, JSON.stringify({{ seq: id, arg }}));
+    }});
+    window['{name}']['callbacks'] = callbacks;
+}})();"#,
+        name = name
+    )
+}
+
+pub(crate) fn add_binding_params(name: impl Into<String>) -> AddBindingParams {
+    AddBindingParams::new(name)
+}
+
+pub(crate) fn add_script_params(script: impl Into<String>) -> AddScriptToEvaluateOnNewDocumentParams {
+    AddScriptToEvaluateOnNewDocumentParams::new(script)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_payload_extracts_seq_and_arg() {
+        let (seq, arg) = split_payload(r#"{"seq":3,"arg":{"a":1}}"#).unwrap();
+        assert_eq!(seq, 3);
+        assert_eq!(arg, r#"{"a":1}"#);
+    }
+
+    #[test]
+    fn split_payload_defaults_missing_arg_to_null() {
+        let (seq, arg) = split_payload(r#"{"seq":0}"#).unwrap();
+        assert_eq!(seq, 0);
+        assert_eq!(arg, "null");
+    }
+
+    #[test]
+    fn split_payload_rejects_missing_seq() {
+        assert!(split_payload(r#"{"arg":1}"#).is_err());
+    }
+
+    #[test]
+    fn install_script_saves_the_original_binding_before_overwriting_it() {
+        let script = install_script("myBinding");
+        assert!(script.contains("const binding = window['myBinding'];"));
+        assert!(script.contains("binding.call(window"));
+    }
+}
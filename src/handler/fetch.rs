@@ -0,0 +1,178 @@
+use std::sync::{Arc, Mutex};
+
+use futures::channel::mpsc::{channel, Receiver, Sender};
+use futures::SinkExt;
+
+use chromiumoxide_cdp::cdp::browser_protocol::fetch::{
+    AuthChallengeResponse, ContinueRequestParams, ContinueWithAuthParams, EnableParams,
+    EventRequestPaused, FailRequestParams, FulfillRequestParams, HeaderEntry, RequestId,
+    RequestPattern,
+};
+
+use crate::error::Result;
+
+/// A request that Chrome has paused because it matched one of the
+/// `RequestPattern`s passed to `enable_request_interception`.
+///
+/// The renderer is blocked until this request is answered with one of the
+/// `RequestPausedDecision` variants, so every `PausedRequest` handed to a
+/// caller must eventually be resolved.
+#[derive(Debug, Clone)]
+pub struct PausedRequest {
+    pub event: EventRequestPaused,
+}
+
+impl PausedRequest {
+    pub fn new(event: EventRequestPaused) -> Self {
+        Self { event }
+    }
+
+    pub fn request_id(&self) -> &RequestId {
+        &self.event.request_id
+    }
+}
+
+/// How a caller wants to answer a paused `Fetch.requestPaused` event.
+///
+/// Mirrors the `RequestPausedDecision` enum from the headless_chrome fork:
+/// every paused request must be answered with exactly one of these.
+#[derive(Debug, Clone)]
+pub enum RequestPausedDecision {
+    /// Let the request continue, optionally rewriting url/method/headers/body.
+    Continue {
+        url: Option<String>,
+        method: Option<String>,
+        post_data: Option<String>,
+        headers: Option<Vec<HeaderEntry>>,
+    },
+    /// Answer the request locally instead of letting it reach the network.
+    Fulfill {
+        response_code: i64,
+        response_headers: Option<Vec<HeaderEntry>>,
+        body: Option<String>,
+    },
+    /// Abort the request with the given network error reason.
+    Fail {
+        error_reason: chromiumoxide_cdp::cdp::browser_protocol::network::ErrorReason,
+    },
+    /// Answer an authentication challenge raised for this request.
+    ContinueWithAuth { response: AuthChallengeResponse },
+}
+
+impl RequestPausedDecision {
+    pub(crate) fn into_continue_params(
+        self,
+        request_id: RequestId,
+    ) -> Option<ContinueRequestParams> {
+        match self {
+            RequestPausedDecision::Continue {
+                url,
+                method,
+                post_data,
+                headers,
+            } => {
+                let mut builder = ContinueRequestParams::builder().request_id(request_id);
+                if let Some(url) = url {
+                    builder = builder.url(url);
+                }
+                if let Some(method) = method {
+                    builder = builder.method(method);
+                }
+                if let Some(post_data) = post_data {
+                    builder = builder.post_data(post_data);
+                }
+                if let Some(headers) = headers {
+                    builder = builder.headers(headers);
+                }
+                Some(builder.build().unwrap())
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn into_fulfill_params(
+        self,
+        request_id: RequestId,
+    ) -> Option<FulfillRequestParams> {
+        match self {
+            RequestPausedDecision::Fulfill {
+                response_code,
+                response_headers,
+                body,
+            } => {
+                let mut builder = FulfillRequestParams::builder()
+                    .request_id(request_id)
+                    .response_code(response_code);
+                if let Some(response_headers) = response_headers {
+                    builder = builder.response_headers(response_headers);
+                }
+                if let Some(body) = body {
+                    builder = builder.body(base64::encode(body));
+                }
+                Some(builder.build().unwrap())
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn into_fail_params(self, request_id: RequestId) -> Option<FailRequestParams> {
+        match self {
+            RequestPausedDecision::Fail { error_reason } => Some(
+                FailRequestParams::builder()
+                    .request_id(request_id)
+                    .error_reason(error_reason)
+                    .build()
+                    .unwrap(),
+            ),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn into_continue_with_auth_params(
+        self,
+        request_id: RequestId,
+    ) -> Option<ContinueWithAuthParams> {
+        match self {
+            RequestPausedDecision::ContinueWithAuth { response } => {
+                Some(ContinueWithAuthParams::new(request_id, response))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Builds the `Fetch.enable` params for a set of `RequestPattern`s and keeps
+/// track of paused-request subscribers so that `Fetch.requestPaused` events
+/// can be routed to the right listener.
+#[derive(Debug, Default)]
+pub(crate) struct FetchHandler {
+    subscribers: Arc<Mutex<Vec<Sender<PausedRequest>>>>,
+}
+
+impl FetchHandler {
+    pub fn enable_params(patterns: Vec<RequestPattern>) -> EnableParams {
+        EnableParams::builder()
+            .patterns(patterns)
+            .handle_auth_requests(true)
+            .build()
+    }
+
+    /// Registers a new subscriber and returns the receiving end of the
+    /// channel that paused requests will be pushed onto.
+    pub fn subscribe(&self) -> Receiver<PausedRequest> {
+        let (tx, rx) = channel(16);
+        self.subscribers.lock().unwrap().push(tx);
+        rx
+    }
+
+    /// Forwards a `Fetch.requestPaused` event to every registered
+    /// subscriber. Called by the target's event loop.
+    pub async fn dispatch(&self, event: EventRequestPaused) {
+        let request = PausedRequest::new(event);
+        let mut subscribers = self.subscribers.lock().unwrap().clone();
+        for subscriber in subscribers.iter_mut() {
+            let _ = subscriber.send(request.clone()).await;
+        }
+    }
+}
+
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use chromiumoxide_cdp::cdp::browser_protocol::dom::SetFileInputFilesParams;
+use chromiumoxide_cdp::cdp::browser_protocol::page::{
+    EventFileChooserOpened, SetInterceptFileChooserDialogParams,
+};
+
+use crate::error::Result;
+use crate::handler::page::PageInner;
+
+pub(crate) fn enable_params() -> SetInterceptFileChooserDialogParams {
+    SetInterceptFileChooserDialogParams::new(true)
+}
+
+/// Responds to an intercepted `Page.fileChooserOpened` event by setting the
+/// given file paths on the associated `<input type="file">` node via
+/// `DOM.setFileInputFiles`.
+pub(crate) async fn set_files(
+    page: &PageInner,
+    event: &EventFileChooserOpened,
+    files: Vec<PathBuf>,
+) -> Result<()> {
+    page.execute(SetFileInputFilesParams::new(
+        files
+            .into_iter()
+            .map(|p| p.to_string_lossy().into_owned())
+            .collect::<Vec<_>>(),
+        event.backend_node_id.clone(),
+    ))
+    .await?;
+    Ok(())
+}
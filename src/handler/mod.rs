@@ -0,0 +1,9 @@
+pub(crate) mod actions;
+pub(crate) mod binding;
+pub(crate) mod fetch;
+pub(crate) mod filechooser;
+pub(crate) mod mouse;
+pub(crate) mod network;
+pub(crate) mod page;
+pub(crate) mod pdf;
+pub(crate) mod window;
@@ -0,0 +1,242 @@
+use std::sync::Mutex;
+
+use futures::channel::mpsc::Sender;
+
+use chromiumoxide_cdp::cdp::browser_protocol::input::{
+    DispatchMouseEventParams, DispatchMouseEventType, MouseButton,
+};
+use chromiumoxide_cdp::cdp::browser_protocol::target::SessionId;
+use chromiumoxide_types::{Command, CommandResponse};
+
+use crate::error::Result;
+use crate::handler::target::TargetMessage;
+use crate::layout::Point;
+
+/// Bitmask values for the `modifiers` field of a dispatched input event,
+/// matching the `UIEvent.modifiers` bitmask used by CDP's `Input` domain.
+pub const MODIFIER_ALT: i64 = 1;
+pub const MODIFIER_CTRL: i64 = 2;
+pub const MODIFIER_META: i64 = 4;
+pub const MODIFIER_SHIFT: i64 = 8;
+
+/// Options for `Mouse::click`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ClickOptions {
+    pub button: Option<MouseButton>,
+    pub click_count: Option<i64>,
+    pub modifiers: Option<i64>,
+}
+
+impl ClickOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn button(mut self, button: MouseButton) -> Self {
+        self.button = Some(button);
+        self
+    }
+
+    pub fn click_count(mut self, click_count: i64) -> Self {
+        self.click_count = Some(click_count);
+        self
+    }
+
+    pub fn modifiers(mut self, modifiers: i64) -> Self {
+        self.modifiers = Some(modifiers);
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct MouseState {
+    position: Point,
+    buttons: Vec<MouseButton>,
+    modifiers: i64,
+}
+
+/// A stateful mouse that remembers the last dispatched position and the
+/// currently pressed buttons, analogous to Puppeteer's `Mouse` class.
+///
+/// Unlike the single-shot `PageInner::move_mouse`/`click` methods this type
+/// is built for interactions that span several events, such as hover,
+/// drag-and-drop and multi-click. A single `Mouse` is created once per page
+/// (see `PageInner::mouse`) and kept around so state survives across calls.
+#[derive(Debug)]
+pub struct Mouse {
+    sender: Sender<TargetMessage>,
+    session_id: SessionId,
+    state: Mutex<MouseState>,
+}
+
+impl Mouse {
+    pub(crate) fn new(sender: Sender<TargetMessage>, session_id: SessionId) -> Self {
+        Self {
+            sender,
+            session_id,
+            state: Mutex::new(MouseState::default()),
+        }
+    }
+
+    async fn execute<T: Command>(&self, cmd: T) -> Result<CommandResponse<T::Response>> {
+        crate::handler::page::execute(cmd, self.sender.clone(), Some(self.session_id.clone())).await
+    }
+
+    fn modifiers(&self) -> i64 {
+        self.state.lock().unwrap().modifiers
+    }
+
+    /// Sets the modifier bitmask applied to every subsequently dispatched
+    /// event, until changed again.
+    pub fn set_modifiers(&self, modifiers: i64) {
+        self.state.lock().unwrap().modifiers = modifiers;
+    }
+
+    /// Moves the mouse to `point`, emitting `steps` intermediate
+    /// `mouseMoved` events linearly interpolated between the last known
+    /// position and `point`.
+    pub async fn move_to(&self, point: Point, steps: u32) -> Result<()> {
+        let (from, modifiers) = {
+            let state = self.state.lock().unwrap();
+            (state.position, state.modifiers)
+        };
+        let steps = steps.max(1);
+
+        for step in 1..=steps {
+            let t = f64::from(step) / f64::from(steps);
+            let intermediate = Point::new(
+                from.x + (point.x - from.x) * t,
+                from.y + (point.y - from.y) * t,
+            );
+            self.execute(
+                    DispatchMouseEventParams::builder()
+                        .r#type(DispatchMouseEventType::MouseMoved)
+                        .x(intermediate.x)
+                        .y(intermediate.y)
+                        .modifiers(modifiers)
+                        .build()
+                        .unwrap(),
+                )
+                .await?;
+        }
+
+        self.state.lock().unwrap().position = point;
+        Ok(())
+    }
+
+    /// Moves the mouse to `point` without pressing any button.
+    pub async fn hover(&self, point: Point) -> Result<()> {
+        self.move_to(point, 1).await
+    }
+
+    /// Presses the given mouse button at the current position.
+    pub async fn down(&self, button: MouseButton) -> Result<()> {
+        let (point, modifiers) = {
+            let state = self.state.lock().unwrap();
+            (state.position, state.modifiers)
+        };
+        self.execute(
+                DispatchMouseEventParams::builder()
+                    .r#type(DispatchMouseEventType::MousePressed)
+                    .x(point.x)
+                    .y(point.y)
+                    .button(button)
+                    .click_count(1)
+                    .modifiers(modifiers)
+                    .build()
+                    .unwrap(),
+            )
+            .await?;
+        self.state.lock().unwrap().buttons.push(button);
+        Ok(())
+    }
+
+    /// Releases the given mouse button at the current position.
+    pub async fn up(&self, button: MouseButton) -> Result<()> {
+        let (point, modifiers) = {
+            let state = self.state.lock().unwrap();
+            (state.position, state.modifiers)
+        };
+        self.execute(
+                DispatchMouseEventParams::builder()
+                    .r#type(DispatchMouseEventType::MouseReleased)
+                    .x(point.x)
+                    .y(point.y)
+                    .button(button)
+                    .click_count(1)
+                    .modifiers(modifiers)
+                    .build()
+                    .unwrap(),
+            )
+            .await?;
+        self.state.lock().unwrap().buttons.retain(|b| b != &button);
+        Ok(())
+    }
+
+    /// Moves to `point` and performs a click with the given options,
+    /// supporting `click_count` for double/triple clicks.
+    pub async fn click(&self, point: Point, opts: ClickOptions) -> Result<()> {
+        let button = opts.button.unwrap_or(MouseButton::Left);
+        let click_count = opts.click_count.unwrap_or(1);
+        let modifiers = opts.modifiers.unwrap_or_else(|| self.modifiers());
+
+        self.move_to(point, 1).await?;
+
+        self.execute(
+                DispatchMouseEventParams::builder()
+                    .r#type(DispatchMouseEventType::MousePressed)
+                    .x(point.x)
+                    .y(point.y)
+                    .button(button)
+                    .click_count(click_count)
+                    .modifiers(modifiers)
+                    .build()
+                    .unwrap(),
+            )
+            .await?;
+        self.execute(
+                DispatchMouseEventParams::builder()
+                    .r#type(DispatchMouseEventType::MouseReleased)
+                    .x(point.x)
+                    .y(point.y)
+                    .button(button)
+                    .click_count(click_count)
+                    .modifiers(modifiers)
+                    .build()
+                    .unwrap(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Presses at `from`, moves through `steps` interpolated positions, then
+    /// releases at `to` — a drag-and-drop gesture.
+    pub async fn drag(&self, from: Point, to: Point, steps: u32) -> Result<()> {
+        self.move_to(from, 1).await?;
+        self.down(MouseButton::Left).await?;
+        self.move_to(to, steps).await?;
+        self.up(MouseButton::Left).await?;
+        Ok(())
+    }
+
+    /// Dispatches a `MouseWheel` event scrolling by `(delta_x, delta_y)`.
+    pub async fn wheel(&self, delta_x: f64, delta_y: f64) -> Result<()> {
+        let (point, modifiers) = {
+            let state = self.state.lock().unwrap();
+            (state.position, state.modifiers)
+        };
+        self.execute(
+                DispatchMouseEventParams::builder()
+                    .r#type(DispatchMouseEventType::MouseWheel)
+                    .x(point.x)
+                    .y(point.y)
+                    .delta_x(delta_x)
+                    .delta_y(delta_y)
+                    .modifiers(modifiers)
+                    .build()
+                    .unwrap(),
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use chromiumoxide_cdp::cdp::browser_protocol::network::{
+    CookieParam, CookieSameSite, DeleteCookiesParams, EnableParams, GetCookiesParams,
+    SetCookiesParams, SetExtraHttpHeadersParams, SetUserAgentOverrideParams,
+};
+
+use crate::error::Result;
+use crate::handler::page::PageInner;
+
+/// A simplified cookie builder so callers don't have to construct the full
+/// `CookieParam` by hand for the common case of setting one cookie.
+#[derive(Debug, Clone, Default)]
+pub struct CookieBuilder {
+    pub name: String,
+    pub value: String,
+    pub url: Option<String>,
+    pub domain: Option<String>,
+    pub path: Option<String>,
+    pub secure: Option<bool>,
+    pub http_only: Option<bool>,
+    pub same_site: Option<CookieSameSite>,
+    pub expires: Option<f64>,
+}
+
+impl CookieBuilder {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = Some(secure);
+        self
+    }
+
+    pub fn http_only(mut self, http_only: bool) -> Self {
+        self.http_only = Some(http_only);
+        self
+    }
+
+    pub fn same_site(mut self, same_site: CookieSameSite) -> Self {
+        self.same_site = Some(same_site);
+        self
+    }
+
+    pub fn expires(mut self, expires: f64) -> Self {
+        self.expires = Some(expires);
+        self
+    }
+}
+
+impl From<CookieBuilder> for CookieParam {
+    fn from(cookie: CookieBuilder) -> Self {
+        let mut builder = CookieParam::builder().name(cookie.name).value(cookie.value);
+        if let Some(url) = cookie.url {
+            builder = builder.url(url);
+        }
+        if let Some(domain) = cookie.domain {
+            builder = builder.domain(domain);
+        }
+        if let Some(path) = cookie.path {
+            builder = builder.path(path);
+        }
+        if let Some(secure) = cookie.secure {
+            builder = builder.secure(secure);
+        }
+        if let Some(http_only) = cookie.http_only {
+            builder = builder.http_only(http_only);
+        }
+        if let Some(same_site) = cookie.same_site {
+            builder = builder.same_site(same_site);
+        }
+        if let Some(expires) = cookie.expires {
+            builder = builder.expires(expires);
+        }
+        builder.build().unwrap()
+    }
+}
+
+/// Tracks whether the `Network` domain has already been enabled for a page,
+/// so the cookie/header/user-agent helpers can enable it lazily on first
+/// use without redundant `Network.enable` calls.
+#[derive(Debug, Default)]
+pub(crate) struct NetworkState {
+    enabled: AtomicBool,
+}
+
+impl NetworkState {
+    pub(crate) async fn ensure_enabled(&self, page: &PageInner) -> Result<()> {
+        if !self.enabled.swap(true, Ordering::SeqCst) {
+            page.execute(EnableParams::default()).await?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn set_cookies_params(cookies: Vec<CookieBuilder>) -> SetCookiesParams {
+    SetCookiesParams::new(cookies.into_iter().map(CookieParam::from).collect())
+}
+
+pub(crate) fn get_cookies_params() -> GetCookiesParams {
+    GetCookiesParams::default()
+}
+
+pub(crate) fn delete_cookies_params(
+    name: impl Into<String>,
+    url: Option<String>,
+    domain: Option<String>,
+    path: Option<String>,
+) -> DeleteCookiesParams {
+    let mut builder = DeleteCookiesParams::builder().name(name);
+    if let Some(url) = url {
+        builder = builder.url(url);
+    }
+    if let Some(domain) = domain {
+        builder = builder.domain(domain);
+    }
+    if let Some(path) = path {
+        builder = builder.path(path);
+    }
+    builder.build().unwrap()
+}
+
+pub(crate) fn set_extra_http_headers_params(
+    headers: HashMap<String, String>,
+) -> SetExtraHttpHeadersParams {
+    SetExtraHttpHeadersParams::new(headers.into())
+}
+
+pub(crate) fn set_user_agent_params(
+    user_agent: impl Into<String>,
+    accept_language: Option<String>,
+    platform: Option<String>,
+) -> SetUserAgentOverrideParams {
+    let mut builder = SetUserAgentOverrideParams::builder().user_agent(user_agent);
+    if let Some(accept_language) = accept_language {
+        builder = builder.accept_language(accept_language);
+    }
+    if let Some(platform) = platform {
+        builder = builder.platform(platform);
+    }
+    builder.build().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cookie_builder_sets_only_the_requested_fields() {
+        let cookie = CookieBuilder::new("session", "abc123")
+            .domain("example.com")
+            .secure(true);
+
+        assert_eq!(cookie.name, "session");
+        assert_eq!(cookie.value, "abc123");
+        assert_eq!(cookie.domain.as_deref(), Some("example.com"));
+        assert_eq!(cookie.secure, Some(true));
+        assert_eq!(cookie.url, None);
+        assert_eq!(cookie.path, None);
+    }
+
+    #[test]
+    fn cookie_builder_converts_into_cookie_param() {
+        let cookie = CookieBuilder::new("session", "abc123")
+            .url("https://example.com")
+            .http_only(true)
+            .same_site(CookieSameSite::Strict);
+        let param: CookieParam = cookie.into();
+
+        assert_eq!(param.name, "session");
+        assert_eq!(param.value, "abc123");
+        assert_eq!(param.http_only, Some(true));
+    }
+}
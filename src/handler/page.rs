@@ -1,4 +1,8 @@
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 
 use futures::channel::mpsc::{channel, Receiver, Sender};
 use futures::channel::oneshot::channel as oneshot_channel;
@@ -9,23 +13,35 @@ use chromiumoxide_cdp::cdp::browser_protocol::browser::{GetVersionParams, GetVer
 use chromiumoxide_cdp::cdp::browser_protocol::dom::{
     NodeId, QuerySelectorAllParams, QuerySelectorParams,
 };
+use chromiumoxide_cdp::cdp::browser_protocol::fetch::RequestPattern;
+use chromiumoxide_cdp::cdp::browser_protocol::network::Cookie;
 use chromiumoxide_cdp::cdp::browser_protocol::input::{
     DispatchKeyEventParams, DispatchKeyEventType, DispatchMouseEventParams, DispatchMouseEventType,
     MouseButton,
 };
 use chromiumoxide_cdp::cdp::browser_protocol::page::{
-    CaptureScreenshotParams, GetLayoutMetricsParams, GetLayoutMetricsReturns,
+    CaptureScreenshotParams, EventFileChooserOpened, GetLayoutMetricsParams,
+    GetLayoutMetricsReturns, PrintToPdfParams,
 };
 use chromiumoxide_cdp::cdp::browser_protocol::target::{ActivateTargetParams, SessionId, TargetId};
 use chromiumoxide_cdp::cdp::js_protocol::runtime::{
-    CallFunctionOnParams, CallFunctionOnReturns, EvaluateParams, ExecutionContextId, RemoteObjectId,
+    CallFunctionOnParams, CallFunctionOnReturns, EvaluateParams, EventBindingCalled,
+    ExecutionContextId, RemoteObjectId,
 };
 use chromiumoxide_types::{Command, CommandResponse};
 
 use crate::cmd::{to_command_response, CommandMessage};
 use crate::error::{CdpError, Result};
 use crate::handler::domworld::DOMWorldKind;
+use crate::handler::actions::{self, Actions};
+use crate::handler::binding::{self, BindingRegistry};
+use crate::handler::fetch::{FetchHandler, PausedRequest, RequestPausedDecision};
+use crate::handler::filechooser;
+use crate::handler::mouse::Mouse;
+use crate::handler::network::{self, CookieBuilder, NetworkState};
+use crate::handler::pdf::{self, PrintToPdfOptions};
 use crate::handler::target::{GetExecutionContext, TargetMessage};
+use crate::handler::window::{self, Bounds};
 use crate::js::EvaluationResult;
 use crate::keys;
 use crate::layout::Point;
@@ -39,10 +55,16 @@ pub struct PageHandle {
 impl PageHandle {
     pub fn new(target_id: TargetId, session_id: SessionId) -> Self {
         let (commands, rx) = channel(1);
+        let mouse = Mouse::new(commands.clone(), session_id.clone());
         let page = PageInner {
             target_id,
             session_id,
             sender: commands,
+            fetch: FetchHandler::default(),
+            network: NetworkState::default(),
+            bindings: BindingRegistry::default(),
+            file_choosers: Mutex::new(Vec::new()),
+            mouse,
         };
         Self {
             rx: rx.fuse(),
@@ -50,6 +72,12 @@ impl PageHandle {
         }
     }
 
+    /// Shares this page's `Arc<PageInner>` with the session's target event
+    /// demultiplexer, so `Fetch.requestPaused`, `Runtime.bindingCalled` and
+    /// `Page.fileChooserOpened` events for this target/session can be routed
+    /// straight to `dispatch_request_paused`/`dispatch_binding_called`/
+    /// `dispatch_file_chooser_opened` as they arrive, without an extra
+    /// queue in between.
     pub(crate) fn inner(&self) -> &Arc<PageInner> {
         &self.page
     }
@@ -60,6 +88,11 @@ pub(crate) struct PageInner {
     target_id: TargetId,
     session_id: SessionId,
     sender: Sender<TargetMessage>,
+    fetch: FetchHandler,
+    network: NetworkState,
+    bindings: BindingRegistry,
+    file_choosers: Mutex<Vec<Sender<EventFileChooserOpened>>>,
+    mouse: Mouse,
 }
 
 impl PageInner {
@@ -127,6 +160,14 @@ impl PageInner {
             .node_ids)
     }
 
+    /// Returns this page's stateful `Mouse`, which tracks position and
+    /// pressed buttons across calls, for hover, drag and multi-click
+    /// gestures. Prefer this over `move_mouse`/`click` when an interaction
+    /// spans several events.
+    pub fn mouse(&self) -> &Mouse {
+        &self.mouse
+    }
+
     /// Moves the mouse to this point (dispatches a mouseMoved event)
     pub async fn move_mouse(&self, point: Point) -> Result<&Self> {
         self.execute(DispatchMouseEventParams::new(
@@ -184,33 +225,15 @@ impl PageInner {
     /// keys.
     pub async fn press_key(&self, key: impl AsRef<str>) -> Result<&Self> {
         let key = key.as_ref();
-        let key_definition = keys::get_key_definition(key)
-            .ok_or_else(|| CdpError::msg(format!("Key not found: {}", key)))?;
-        let mut cmd = DispatchKeyEventParams::builder();
-
-        // See https://github.com/GoogleChrome/puppeteer/blob/62da2366c65b335751896afbb0206f23c61436f1/lib/Input.js#L114-L115
-        // And https://github.com/GoogleChrome/puppeteer/blob/62da2366c65b335751896afbb0206f23c61436f1/lib/Input.js#L52
-        let key_down_event_type = if let Some(txt) = key_definition.text {
-            cmd = cmd.text(txt);
-            DispatchKeyEventType::KeyDown
-        } else if key_definition.key.len() == 1 {
-            cmd = cmd.text(key_definition.key);
-            DispatchKeyEventType::KeyDown
-        } else {
-            DispatchKeyEventType::RawKeyDown
-        };
-
-        cmd = cmd
-            .r#type(DispatchKeyEventType::KeyDown)
-            .key(key_definition.key)
-            .code(key_definition.code)
-            .windows_virtual_key_code(key_definition.key_code)
-            .native_virtual_key_code(key_definition.key_code);
+        self.execute(key_event_params(key, true)?).await?;
+        self.execute(key_event_params(key, false)?).await?;
+        Ok(self)
+    }
 
-        self.execute(cmd.clone().r#type(key_down_event_type).build().unwrap())
-            .await?;
-        self.execute(cmd.r#type(DispatchKeyEventType::KeyUp).build().unwrap())
-            .await?;
+    /// Replays a chained `Actions` sequence of simultaneous key/pointer
+    /// sub-actions, e.g. holding a modifier down across a click.
+    pub async fn perform_actions(&self, actions: Actions) -> Result<&Self> {
+        actions::perform(self, actions).await?;
         Ok(self)
     }
 
@@ -321,6 +344,236 @@ impl PageInner {
         let res = self.execute(params).await?.result;
         Ok(base64::decode(&res.data)?)
     }
+
+    /// Prints the page to PDF via `Page.printToPDF` and returns the decoded
+    /// bytes. Requests the streaming transfer mode unless
+    /// `options.transfer_mode` says otherwise, so large PDFs don't exceed
+    /// the JSON response size limit.
+    pub async fn print_to_pdf(&self, options: PrintToPdfOptions) -> Result<Vec<u8>> {
+        self.activate().await?;
+        let params: PrintToPdfParams = options.into();
+        let res = self.execute(params).await?.result;
+        if let Some(stream) = res.stream {
+            pdf::read_stream(self, stream).await
+        } else if let Some(data) = res.data {
+            Ok(base64::decode(&data)?)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Enables the `Fetch` domain for the given patterns and returns a
+    /// stream of paused requests. Every item received must be answered with
+    /// `respond_to_request` and one of the `RequestPausedDecision` variants,
+    /// otherwise the renderer stays blocked waiting for a decision.
+    pub async fn enable_request_interception(
+        &self,
+        patterns: Vec<RequestPattern>,
+    ) -> Result<Receiver<PausedRequest>> {
+        self.execute(FetchHandler::enable_params(patterns)).await?;
+        Ok(self.fetch.subscribe())
+    }
+
+    /// Dispatches a single paused request to the registered subscribers.
+    ///
+    /// Called directly by the session's target event demultiplexer when a
+    /// `Fetch.requestPaused` event arrives for this page's session id.
+    pub(crate) async fn dispatch_request_paused(
+        &self,
+        event: chromiumoxide_cdp::cdp::browser_protocol::fetch::EventRequestPaused,
+    ) {
+        self.fetch.dispatch(event).await
+    }
+
+    /// Exposes a Rust closure to page JavaScript as `window.<name>`. Page
+    /// script calling `window.<name>(arg)` gets a promise that resolves
+    /// with `handler`'s return value once it completes.
+    ///
+    /// Registers the binding for all future navigations via
+    /// `Page.addScriptToEvaluateOnNewDocument`, and also evaluates it
+    /// against the page's current execution context so `window.<name>` is
+    /// callable immediately, without requiring a reload first.
+    pub async fn expose_function<F, Fut>(&self, name: impl Into<String>, handler: F) -> Result<&Self>
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String>> + Send + 'static,
+    {
+        let name = name.into();
+        self.execute(binding::add_binding_params(name.clone()))
+            .await?;
+        let script = binding::install_script(&name);
+        self.execute(binding::add_script_params(script.clone()))
+            .await?;
+        self.evaluate_expression(script).await?;
+        self.bindings.insert(
+            name,
+            Box::new(move |arg| Box::pin(handler(arg)) as Pin<Box<dyn Future<Output = Result<String>> + Send>>),
+        );
+        Ok(self)
+    }
+
+    /// Routes a `Runtime.bindingCalled` event to the matching registered
+    /// closure and settles the pending JS promise with its result. Called
+    /// directly by the session's target event demultiplexer.
+    pub(crate) async fn dispatch_binding_called(&self, event: EventBindingCalled) -> Result<()> {
+        self.bindings.dispatch(self, event).await
+    }
+
+    /// Sets one or more cookies, enabling the `Network` domain on first use.
+    pub async fn set_cookies(&self, cookies: Vec<CookieBuilder>) -> Result<&Self> {
+        self.network.ensure_enabled(self).await?;
+        self.execute(network::set_cookies_params(cookies)).await?;
+        Ok(self)
+    }
+
+    /// Returns all cookies visible to the page, enabling the `Network`
+    /// domain on first use.
+    pub async fn get_cookies(&self) -> Result<Vec<Cookie>> {
+        self.network.ensure_enabled(self).await?;
+        Ok(self
+            .execute(network::get_cookies_params())
+            .await?
+            .result
+            .cookies)
+    }
+
+    /// Deletes a cookie matching `name` (and the optional url/domain/path
+    /// filters), enabling the `Network` domain on first use.
+    pub async fn delete_cookies(
+        &self,
+        name: impl Into<String>,
+        url: Option<String>,
+        domain: Option<String>,
+        path: Option<String>,
+    ) -> Result<&Self> {
+        self.network.ensure_enabled(self).await?;
+        self.execute(network::delete_cookies_params(name, url, domain, path))
+            .await?;
+        Ok(self)
+    }
+
+    /// Sets extra HTTP headers sent with every subsequent request, enabling
+    /// the `Network` domain on first use.
+    pub async fn set_extra_http_headers(&self, headers: HashMap<String, String>) -> Result<&Self> {
+        self.network.ensure_enabled(self).await?;
+        self.execute(network::set_extra_http_headers_params(headers))
+            .await?;
+        Ok(self)
+    }
+
+    /// Overrides the user agent (and optionally accept-language/platform)
+    /// reported by subsequent requests, enabling the `Network` domain on
+    /// first use.
+    pub async fn set_user_agent(
+        &self,
+        user_agent: impl Into<String>,
+        accept_language: Option<String>,
+        platform: Option<String>,
+    ) -> Result<&Self> {
+        self.network.ensure_enabled(self).await?;
+        self.execute(network::set_user_agent_params(
+            user_agent,
+            accept_language,
+            platform,
+        ))
+        .await?;
+        Ok(self)
+    }
+
+    /// Returns the position, size and state of the browser window backing
+    /// this page's target.
+    pub async fn get_bounds(&self) -> Result<Bounds> {
+        window::get_bounds(self).await
+    }
+
+    /// Moves/resizes/minimizes/maximizes the browser window backing this
+    /// page's target.
+    pub async fn set_bounds(&self, bounds: Bounds) -> Result<&Self> {
+        window::set_bounds(self, bounds).await?;
+        Ok(self)
+    }
+
+    /// Enables `Page.setInterceptFileChooserDialog` and returns a stream of
+    /// `Page.fileChooserOpened` events. Respond to each with
+    /// `set_file_chooser_files`, which drives `DOM.setFileInputFiles` for
+    /// the associated node.
+    pub async fn intercept_file_chooser(&self) -> Result<Receiver<EventFileChooserOpened>> {
+        self.execute(filechooser::enable_params()).await?;
+        let (tx, rx) = channel(16);
+        self.file_choosers.lock().unwrap().push(tx);
+        Ok(rx)
+    }
+
+    /// Sets the given file paths on the `<input type="file">` node
+    /// associated with a previously received file-chooser event.
+    pub async fn set_file_chooser_files(
+        &self,
+        event: &EventFileChooserOpened,
+        files: Vec<PathBuf>,
+    ) -> Result<&Self> {
+        filechooser::set_files(self, event, files).await?;
+        Ok(self)
+    }
+
+    /// Dispatches a `Page.fileChooserOpened` event to every registered
+    /// subscriber. Called directly by the session's target event
+    /// demultiplexer.
+    pub(crate) async fn dispatch_file_chooser_opened(&self, event: EventFileChooserOpened) {
+        let mut subscribers = self.file_choosers.lock().unwrap().clone();
+        for subscriber in subscribers.iter_mut() {
+            let _ = subscriber.send(event.clone()).await;
+        }
+    }
+
+    /// Resolves a previously paused request with the given decision.
+    pub async fn respond_to_request(
+        &self,
+        request: &PausedRequest,
+        decision: RequestPausedDecision,
+    ) -> Result<&Self> {
+        let request_id = request.request_id().clone();
+        if let Some(cmd) = decision.clone().into_continue_params(request_id.clone()) {
+            self.execute(cmd).await?;
+        } else if let Some(cmd) = decision.clone().into_fulfill_params(request_id.clone()) {
+            self.execute(cmd).await?;
+        } else if let Some(cmd) = decision.clone().into_fail_params(request_id.clone()) {
+            self.execute(cmd).await?;
+        } else if let Some(cmd) = decision.into_continue_with_auth_params(request_id) {
+            self.execute(cmd).await?;
+        }
+        Ok(self)
+    }
+}
+
+/// Builds the `DispatchKeyEventParams` for pressing (`down = true`) or
+/// releasing `key`, shared by `PageInner::press_key` and
+/// `actions::dispatch_key` so both resolve the down event's type/text the
+/// same way.
+///
+/// See https://github.com/GoogleChrome/puppeteer/blob/62da2366c65b335751896afbb0206f23c61436f1/lib/Input.js#L114-L115
+/// and https://github.com/GoogleChrome/puppeteer/blob/62da2366c65b335751896afbb0206f23c61436f1/lib/Input.js#L52
+pub(crate) fn key_event_params(key: &str, down: bool) -> Result<DispatchKeyEventParams> {
+    let key_definition = keys::get_key_definition(key)
+        .ok_or_else(|| CdpError::msg(format!("Key not found: {}", key)))?;
+    let mut cmd = DispatchKeyEventParams::builder()
+        .key(key_definition.key)
+        .code(key_definition.code)
+        .windows_virtual_key_code(key_definition.key_code)
+        .native_virtual_key_code(key_definition.key_code);
+
+    let event_type = if !down {
+        DispatchKeyEventType::KeyUp
+    } else if let Some(txt) = key_definition.text {
+        cmd = cmd.text(txt);
+        DispatchKeyEventType::KeyDown
+    } else if key_definition.key.len() == 1 {
+        cmd = cmd.text(key_definition.key);
+        DispatchKeyEventType::KeyDown
+    } else {
+        DispatchKeyEventType::RawKeyDown
+    };
+
+    Ok(cmd.r#type(event_type).build().unwrap())
 }
 
 pub(crate) async fn execute<T: Command>(
@@ -0,0 +1,101 @@
+use chromiumoxide_cdp::cdp::browser_protocol::io::{CloseParams, ReadParams, StreamHandle};
+use chromiumoxide_cdp::cdp::browser_protocol::page::{PrintToPdfParams, TransferMode};
+
+use crate::error::Result;
+use crate::handler::page::PageInner;
+
+/// Options for `PageInner::print_to_pdf`, covering the fields exposed by
+/// `Page.printToPDF`.
+#[derive(Debug, Clone, Default)]
+pub struct PrintToPdfOptions {
+    pub landscape: Option<bool>,
+    pub print_background: Option<bool>,
+    pub scale: Option<f64>,
+    pub paper_width: Option<f64>,
+    pub paper_height: Option<f64>,
+    pub margin_top: Option<f64>,
+    pub margin_bottom: Option<f64>,
+    pub margin_left: Option<f64>,
+    pub margin_right: Option<f64>,
+    pub page_ranges: Option<String>,
+    pub header_template: Option<String>,
+    pub footer_template: Option<String>,
+    pub display_header_footer: Option<bool>,
+    pub prefer_css_page_size: Option<bool>,
+    /// How the PDF is returned. Defaults to `ReturnAsStream` so large PDFs
+    /// don't exceed the JSON response size limit; pass
+    /// `Some(TransferMode::ReturnAsBase64)` for the simple inline response.
+    pub transfer_mode: Option<TransferMode>,
+}
+
+impl From<PrintToPdfOptions> for PrintToPdfParams {
+    fn from(opts: PrintToPdfOptions) -> Self {
+        let mut builder = PrintToPdfParams::builder();
+        if let Some(landscape) = opts.landscape {
+            builder = builder.landscape(landscape);
+        }
+        if let Some(print_background) = opts.print_background {
+            builder = builder.print_background(print_background);
+        }
+        if let Some(scale) = opts.scale {
+            builder = builder.scale(scale);
+        }
+        if let Some(paper_width) = opts.paper_width {
+            builder = builder.paper_width(paper_width);
+        }
+        if let Some(paper_height) = opts.paper_height {
+            builder = builder.paper_height(paper_height);
+        }
+        if let Some(margin_top) = opts.margin_top {
+            builder = builder.margin_top(margin_top);
+        }
+        if let Some(margin_bottom) = opts.margin_bottom {
+            builder = builder.margin_bottom(margin_bottom);
+        }
+        if let Some(margin_left) = opts.margin_left {
+            builder = builder.margin_left(margin_left);
+        }
+        if let Some(margin_right) = opts.margin_right {
+            builder = builder.margin_right(margin_right);
+        }
+        if let Some(page_ranges) = opts.page_ranges {
+            builder = builder.page_ranges(page_ranges);
+        }
+        if let Some(header_template) = opts.header_template {
+            builder = builder.header_template(header_template);
+        }
+        if let Some(footer_template) = opts.footer_template {
+            builder = builder.footer_template(footer_template);
+        }
+        if let Some(display_header_footer) = opts.display_header_footer {
+            builder = builder.display_header_footer(display_header_footer);
+        }
+        if let Some(prefer_css_page_size) = opts.prefer_css_page_size {
+            builder = builder.prefer_css_page_size(prefer_css_page_size);
+        }
+        builder
+            .transfer_mode(opts.transfer_mode.unwrap_or(TransferMode::ReturnAsStream))
+            .build()
+    }
+}
+
+/// Reads a `IO.StreamHandle` in chunks until EOF, base64-decoding and
+/// concatenating each chunk, then closes the handle. Used whenever
+/// `Page.printToPDF` returns a stream handle instead of inline data, which
+/// it does for `TransferMode::ReturnAsStream` (the default of
+/// `PrintToPdfOptions`), avoiding the JSON response size limit.
+pub(crate) async fn read_stream(page: &PageInner, handle: StreamHandle) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+    loop {
+        let chunk = page
+            .execute(ReadParams::builder().handle(handle.clone()).build().unwrap())
+            .await?
+            .result;
+        data.extend_from_slice(&base64::decode(&chunk.data)?);
+        if chunk.eof {
+            break;
+        }
+    }
+    page.execute(CloseParams::new(handle)).await?;
+    Ok(data)
+}
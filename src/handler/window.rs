@@ -0,0 +1,148 @@
+use chromiumoxide_cdp::cdp::browser_protocol::browser::{
+    Bounds as CdpBounds, GetWindowBoundsParams, GetWindowForTargetParams, SetWindowBoundsParams,
+    WindowState as CdpWindowState,
+};
+
+use crate::error::Result;
+use crate::handler::page::PageInner;
+
+/// The state of a browser window, mirroring `Browser.WindowState`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowState {
+    Normal,
+    Minimized,
+    Maximized,
+    Fullscreen,
+}
+
+impl From<WindowState> for CdpWindowState {
+    fn from(state: WindowState) -> Self {
+        match state {
+            WindowState::Normal => CdpWindowState::Normal,
+            WindowState::Minimized => CdpWindowState::Minimized,
+            WindowState::Maximized => CdpWindowState::Maximized,
+            WindowState::Fullscreen => CdpWindowState::Fullscreen,
+        }
+    }
+}
+
+impl From<CdpWindowState> for WindowState {
+    fn from(state: CdpWindowState) -> Self {
+        match state {
+            CdpWindowState::Normal => WindowState::Normal,
+            CdpWindowState::Minimized => WindowState::Minimized,
+            CdpWindowState::Maximized => WindowState::Maximized,
+            CdpWindowState::Fullscreen => WindowState::Fullscreen,
+        }
+    }
+}
+
+/// Position, size and state of a browser window.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Bounds {
+    pub left: Option<i64>,
+    pub top: Option<i64>,
+    pub width: Option<i64>,
+    pub height: Option<i64>,
+    pub window_state: Option<WindowState>,
+}
+
+impl From<CdpBounds> for Bounds {
+    fn from(bounds: CdpBounds) -> Self {
+        Self {
+            left: bounds.left,
+            top: bounds.top,
+            width: bounds.width,
+            height: bounds.height,
+            window_state: bounds.window_state.map(WindowState::from),
+        }
+    }
+}
+
+impl From<Bounds> for CdpBounds {
+    fn from(bounds: Bounds) -> Self {
+        let mut builder = CdpBounds::builder();
+        if let Some(left) = bounds.left {
+            builder = builder.left(left);
+        }
+        if let Some(top) = bounds.top {
+            builder = builder.top(top);
+        }
+        if let Some(width) = bounds.width {
+            builder = builder.width(width);
+        }
+        if let Some(height) = bounds.height {
+            builder = builder.height(height);
+        }
+        if let Some(window_state) = bounds.window_state {
+            builder = builder.window_state(CdpWindowState::from(window_state));
+        }
+        builder.build()
+    }
+}
+
+/// Resolves the browser window id backing this page's target, then calls
+/// `Browser.getWindowBounds`.
+pub(crate) async fn get_bounds(page: &PageInner) -> Result<Bounds> {
+    let window = page
+        .execute(GetWindowForTargetParams::new(page.target_id().clone()))
+        .await?
+        .result;
+    let bounds = page
+        .execute(GetWindowBoundsParams::new(window.window_id))
+        .await?
+        .result
+        .bounds;
+    Ok(Bounds::from(bounds))
+}
+
+/// Resolves the browser window id backing this page's target, then calls
+/// `Browser.setWindowBounds` with the given bounds.
+pub(crate) async fn set_bounds(page: &PageInner, bounds: Bounds) -> Result<()> {
+    let window = page
+        .execute(GetWindowForTargetParams::new(page.target_id().clone()))
+        .await?
+        .result;
+    page.execute(SetWindowBoundsParams::new(
+        window.window_id,
+        CdpBounds::from(bounds),
+    ))
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_state_round_trips_through_the_cdp_type() {
+        for state in [
+            WindowState::Normal,
+            WindowState::Minimized,
+            WindowState::Maximized,
+            WindowState::Fullscreen,
+        ] {
+            assert_eq!(WindowState::from(CdpWindowState::from(state)), state);
+        }
+    }
+
+    #[test]
+    fn bounds_only_sets_provided_fields_on_the_cdp_type() {
+        let bounds = Bounds {
+            left: Some(10),
+            top: Some(20),
+            width: None,
+            height: None,
+            window_state: Some(WindowState::Maximized),
+        };
+
+        let cdp_bounds: CdpBounds = bounds.into();
+
+        assert_eq!(cdp_bounds.left, Some(10));
+        assert_eq!(cdp_bounds.top, Some(20));
+        assert_eq!(cdp_bounds.width, None);
+        assert_eq!(cdp_bounds.height, None);
+        assert_eq!(cdp_bounds.window_state, Some(CdpWindowState::Maximized));
+    }
+}